@@ -0,0 +1,151 @@
+use anyhow::{Context, Result};
+use git2::{ErrorCode, Repository, Sort, Status, StatusOptions};
+
+/// A single entry in the commit log (History tab).
+pub struct CommitInfo {
+    pub hash: String,
+    pub summary: String,
+    pub author: String,
+    pub date: String,
+}
+
+/// Staged/modified/untracked file lists (Status and Changes tabs).
+#[derive(Default)]
+pub struct FileLists {
+    pub staged: Vec<String>,
+    pub modified: Vec<String>,
+    pub untracked: Vec<String>,
+}
+
+/// Thin wrapper around `git2::Repository` that exposes just the
+/// state the TUI needs, in a form the UI can render directly.
+pub struct Repo {
+    inner: Repository,
+}
+
+impl Repo {
+    /// Opens the repository at the current working directory.
+    pub fn open_cwd() -> Result<Repo> {
+        let inner = Repository::open(".").context("failed to open git repository in CWD")?;
+        Ok(Repo { inner })
+    }
+
+    /// Name of the currently checked out branch, or "HEAD (detached)" if none.
+    pub fn current_branch(&self) -> String {
+        match self.inner.head() {
+            Ok(head) if head.is_branch() => head
+                .shorthand()
+                .unwrap_or("HEAD (detached)")
+                .to_string(),
+            _ => "HEAD (detached)".to_string(),
+        }
+    }
+
+    /// Splits the working tree status into staged, modified and untracked paths.
+    pub fn file_lists(&self) -> Result<FileLists> {
+        let mut opts = StatusOptions::new();
+        opts.include_untracked(true).recurse_untracked_dirs(true);
+
+        let statuses = self
+            .inner
+            .statuses(Some(&mut opts))
+            .context("failed to read repository status")?;
+
+        let mut lists = FileLists::default();
+        for entry in statuses.iter() {
+            let Some(path) = entry.path() else { continue };
+            let status = entry.status();
+
+            if status.intersects(
+                Status::INDEX_NEW
+                    | Status::INDEX_MODIFIED
+                    | Status::INDEX_DELETED
+                    | Status::INDEX_RENAMED
+                    | Status::INDEX_TYPECHANGE,
+            ) {
+                lists.staged.push(path.to_string());
+            }
+            if status.intersects(
+                Status::WT_MODIFIED | Status::WT_DELETED | Status::WT_TYPECHANGE | Status::WT_RENAMED,
+            ) {
+                lists.modified.push(path.to_string());
+            }
+            if status.contains(Status::WT_NEW) {
+                lists.untracked.push(path.to_string());
+            }
+        }
+
+        Ok(lists)
+    }
+
+    /// Returns the most recent `limit` commits reachable from HEAD, newest
+    /// first. Returns an empty list rather than erroring on a freshly
+    /// initialized repository with no commits yet (an "unborn" HEAD).
+    pub fn commit_log(&self, limit: usize) -> Result<Vec<CommitInfo>> {
+        let mut revwalk = self.inner.revwalk().context("failed to start revwalk")?;
+        if let Err(err) = revwalk.push_head() {
+            if err.code() == ErrorCode::UnbornBranch {
+                return Ok(Vec::new());
+            }
+            return Err(err).context("repository has no HEAD commit");
+        }
+        // git2's default sort order is arbitrary and implementation-specific;
+        // without this the History tab's order could change between runs.
+        revwalk
+            .set_sorting(Sort::TIME)
+            .context("failed to set revwalk sort order")?;
+
+        let mut commits = Vec::with_capacity(limit);
+        for oid in revwalk.take(limit) {
+            let oid = oid.context("failed to read commit id")?;
+            let commit = self.inner.find_commit(oid).context("failed to find commit")?;
+
+            let summary = commit.summary().unwrap_or("<no summary>").to_string();
+            let author = commit.author();
+            let author_name = author.name().unwrap_or("unknown").to_string();
+            let date = chrono_date(commit.time());
+
+            commits.push(CommitInfo {
+                hash: oid.to_string()[..7.min(oid.to_string().len())].to_string(),
+                summary,
+                author: author_name,
+                date,
+            });
+        }
+
+        Ok(commits)
+    }
+}
+
+/// Formats a git commit time as `YYYY-MM-DD HH:MM` in its recorded offset,
+/// without pulling in a full date/time crate.
+fn chrono_date(time: git2::Time) -> String {
+    let offset_secs = time.offset_minutes() as i64 * 60;
+    let local_secs = time.seconds() + offset_secs;
+
+    const SECS_PER_DAY: i64 = 86_400;
+    let days_since_epoch = local_secs.div_euclid(SECS_PER_DAY);
+    let secs_of_day = local_secs.rem_euclid(SECS_PER_DAY);
+
+    let (year, month, day) = civil_from_days(days_since_epoch);
+    let hour = secs_of_day / 3600;
+    let minute = (secs_of_day % 3600) / 60;
+
+    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}")
+}
+
+/// Converts a day count since the Unix epoch into a (year, month, day) civil
+/// date, using Howard Hinnant's well-known proleptic Gregorian algorithm.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+    (y, m, d)
+}
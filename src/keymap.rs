@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use crate::terminal::Key;
+
+/// Something the user can trigger from the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NextTab,
+    PrevTab,
+    Refresh,
+    ScrollUp,
+    ScrollDown,
+    PageUp,
+    PageDown,
+}
+
+/// Maps normalized key presses to [`Action`]s, so controls can be rebound
+/// instead of being matched on literal `KeyCode`s throughout `run_app`.
+pub struct Keymap {
+    bindings: HashMap<Key, Action>,
+}
+
+impl Keymap {
+    /// `q` quits, `r` refreshes, Tab/Shift+Tab (or vim-style `l`/`h`) switch
+    /// tabs, and Up/Down (or `k`/`j`) and PageUp/PageDown scroll. The
+    /// Left/Right arrow keys are not bound.
+    pub fn defaults() -> Keymap {
+        let mut bindings = HashMap::new();
+        bindings.insert(Key::Char('q'), Action::Quit);
+        bindings.insert(Key::Tab, Action::NextTab);
+        bindings.insert(Key::Char('l'), Action::NextTab);
+        bindings.insert(Key::BackTab, Action::PrevTab);
+        bindings.insert(Key::Char('h'), Action::PrevTab);
+        bindings.insert(Key::Char('r'), Action::Refresh);
+        bindings.insert(Key::Up, Action::ScrollUp);
+        bindings.insert(Key::Char('k'), Action::ScrollUp);
+        bindings.insert(Key::Down, Action::ScrollDown);
+        bindings.insert(Key::Char('j'), Action::ScrollDown);
+        bindings.insert(Key::PageUp, Action::PageUp);
+        bindings.insert(Key::PageDown, Action::PageDown);
+        Keymap { bindings }
+    }
+
+    /// Binds `key` to `action`, replacing whatever it was previously bound
+    /// to (if anything) and removing any other key still bound to
+    /// `action`, so the old binding can't linger as a second, undiscoverable
+    /// way to trigger it. This is how callers rebind controls on top of
+    /// [`defaults`](Keymap::defaults) instead of being stuck with them.
+    pub fn bind(&mut self, key: Key, action: Action) {
+        self.bindings.retain(|_, a| *a != action);
+        self.bindings.insert(key, action);
+    }
+
+    /// Overrides the defaults with `GIT_TUI_KEY_<ACTION>` environment
+    /// variables, e.g. `GIT_TUI_KEY_QUIT=x`. There's no config file
+    /// anywhere else in the crate, so this is the lightweight way to let
+    /// users actually rebind controls without one. Unset or unparseable
+    /// values are left at their default binding.
+    pub fn apply_env_overrides(&mut self) {
+        const OVERRIDES: &[(&str, Action)] = &[
+            ("GIT_TUI_KEY_QUIT", Action::Quit),
+            ("GIT_TUI_KEY_NEXT_TAB", Action::NextTab),
+            ("GIT_TUI_KEY_PREV_TAB", Action::PrevTab),
+            ("GIT_TUI_KEY_REFRESH", Action::Refresh),
+            ("GIT_TUI_KEY_SCROLL_UP", Action::ScrollUp),
+            ("GIT_TUI_KEY_SCROLL_DOWN", Action::ScrollDown),
+            ("GIT_TUI_KEY_PAGE_UP", Action::PageUp),
+            ("GIT_TUI_KEY_PAGE_DOWN", Action::PageDown),
+        ];
+
+        for (var, action) in OVERRIDES {
+            if let Ok(value) = std::env::var(var) {
+                if let Some(key) = parse_key(&value) {
+                    self.bind(key, *action);
+                }
+            }
+        }
+    }
+
+    /// Looks up the action bound to `key`, if any.
+    pub fn action_for(&self, key: Key) -> Option<Action> {
+        self.bindings.get(&key).copied()
+    }
+
+    /// A key bound to `action`, if any. Used to render the *current*
+    /// binding in help text instead of a hardcoded literal.
+    pub fn key_for(&self, action: Action) -> Option<Key> {
+        self.bindings
+            .iter()
+            .find(|(_, a)| **a == action)
+            .map(|(k, _)| *k)
+    }
+}
+
+/// Parses a human-typed key name (as it'd appear in an env var) into a
+/// [`Key`]. Named keys match case-sensitively against their `Key` variant
+/// name; anything else is taken as a single literal character.
+fn parse_key(s: &str) -> Option<Key> {
+    match s {
+        "Tab" => Some(Key::Tab),
+        "BackTab" => Some(Key::BackTab),
+        "Up" => Some(Key::Up),
+        "Down" => Some(Key::Down),
+        "Left" => Some(Key::Left),
+        "Right" => Some(Key::Right),
+        "PageUp" => Some(Key::PageUp),
+        "PageDown" => Some(Key::PageDown),
+        "Enter" => Some(Key::Enter),
+        "Esc" => Some(Key::Esc),
+        "Backspace" => Some(Key::Backspace),
+        _ if s.chars().count() == 1 => s.chars().next().map(Key::Char),
+        _ => None,
+    }
+}
@@ -1,33 +1,50 @@
-use std::{
-    io::{self, stdout},
-    time::{Duration, Instant},
-};
+mod git;
+mod keymap;
+mod terminal;
+
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
-    execute,
-    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-};
 use ratatui::{
-    backend::CrosstermBackend,
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Paragraph, Tabs},
     Frame, Terminal,
 };
 
+use git::{CommitInfo, FileLists, Repo};
+use keymap::{Action, Keymap};
+use terminal::{Event, Events, Mouse};
+
+/// How many commits to pull into the History tab on each refresh.
+const HISTORY_LIMIT: usize = 50;
+
 struct App {
     current_tab: usize,
     tabs: Vec<String>,
     status: String,
     branch: String,
+    repo: Repo,
+    files: FileLists,
+    commits: Vec<CommitInfo>,
+    scroll: u16,
+    /// Area the tab bar was last rendered into, used to hit-test mouse clicks.
+    tab_bar_rect: Rect,
+    keymap: Keymap,
 }
 
 impl App {
-    fn new() -> App {
-        App {
+    fn new() -> Result<App> {
+        let repo = Repo::open_cwd()?;
+        let branch = repo.current_branch();
+        let files = repo.file_lists()?;
+        let commits = repo.commit_log(HISTORY_LIMIT)?;
+
+        let mut keymap = Keymap::defaults();
+        keymap.apply_env_overrides();
+
+        Ok(App {
             current_tab: 0,
             tabs: vec![
                 "Status".to_string(),
@@ -36,31 +53,85 @@ impl App {
                 "Settings".to_string(),
             ],
             status: "Ready".to_string(),
-            branch: "main".to_string(),
+            branch,
+            repo,
+            files,
+            commits,
+            scroll: 0,
+            tab_bar_rect: Rect::default(),
+            keymap,
+        })
+    }
+
+    /// Re-reads branch, file status and commit log from disk.
+    fn refresh(&mut self) -> Result<()> {
+        self.branch = self.repo.current_branch();
+        self.files = self.repo.file_lists()?;
+        self.commits = self.repo.commit_log(HISTORY_LIMIT)?;
+        Ok(())
+    }
+
+    /// Scrolls the content pane, clamping at the top.
+    fn scroll_by(&mut self, delta: i16) {
+        self.scroll = self.scroll.saturating_add_signed(delta);
+    }
+
+    /// Returns the tab index whose title is rendered under `(col, row)` in
+    /// the tab bar's `Tabs` widget, if any.
+    fn tab_at(&self, col: u16, row: u16) -> Option<usize> {
+        let inner = self.tab_bar_rect;
+        if inner.width < 2 || inner.height < 2 || row != inner.y + 1 {
+            return None;
+        }
+        let right_edge = inner.x + inner.width - 1;
+
+        let mut x = inner.x + 1;
+        for (i, title) in self.tabs.iter().enumerate() {
+            // Mirrors ratatui's default Tabs layout: a leading/trailing
+            // space of padding around each title, plus a 1-wide divider
+            // between entries.
+            let width = title.chars().count() as u16 + 2;
+            if col >= right_edge {
+                return None;
+            }
+            if col >= x && col < x + width {
+                return Some(i);
+            }
+            x += width + 1;
         }
+        None
+    }
+}
+
+/// RAII guard that restores the terminal when dropped, so a `?` early
+/// return or an `unwind` from a panic can't leave raw mode/the alternate
+/// screen enabled.
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = terminal::restore();
     }
 }
 
 fn main() -> Result<()> {
-    // Terminal initialization
-    enable_raw_mode()?;
-    let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    // Chain onto the default panic hook so a panic still restores the
+    // terminal before the report is printed, instead of leaving it wrecked.
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = terminal::restore();
+        default_hook(info);
+    }));
+
+    let mut term = terminal::init()?;
+    let guard = TerminalGuard;
 
     // Create app and run it
-    let app = App::new();
-    let res = run_app(&mut terminal, app);
+    let app = App::new()?;
+    let res = run_app(&mut term, app);
 
     // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    drop(guard);
 
     if let Err(err) = res {
         println!("{err:?}");
@@ -69,44 +140,64 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, mut app: App) -> io::Result<()> {
+fn run_app<B: ratatui::backend::Backend>(term: &mut Terminal<B>, mut app: App) -> Result<()> {
     let tick_rate = Duration::from_millis(250);
     let mut last_tick = Instant::now();
+    let events = Events::new();
 
     loop {
-        terminal.draw(|f| ui(f, &app))?;
+        term.draw(|f| ui(f, &mut app))?;
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
-        if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                match key.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Tab => {
-                        app.current_tab = (app.current_tab + 1) % app.tabs.len();
-                    }
-                    KeyCode::BackTab => {
-                        app.current_tab = if app.current_tab > 0 {
-                            app.current_tab - 1
-                        } else {
-                            app.tabs.len() - 1
-                        };
+        match events.next(timeout)? {
+            Event::Key(key) => {
+                if let Some(action) = app.keymap.action_for(key) {
+                    match action {
+                        Action::Quit => return Ok(()),
+                        Action::NextTab => {
+                            app.current_tab = (app.current_tab + 1) % app.tabs.len();
+                            app.scroll = 0;
+                        }
+                        Action::PrevTab => {
+                            app.current_tab = if app.current_tab > 0 {
+                                app.current_tab - 1
+                            } else {
+                                app.tabs.len() - 1
+                            };
+                            app.scroll = 0;
+                        }
+                        Action::Refresh => app.refresh()?,
+                        Action::ScrollUp => app.scroll_by(-1),
+                        Action::ScrollDown => app.scroll_by(1),
+                        Action::PageUp => app.scroll_by(-10),
+                        Action::PageDown => app.scroll_by(10),
                     }
-                    _ => {}
                 }
             }
+            Event::Mouse(Mouse::Down(col, row)) => {
+                if let Some(tab) = app.tab_at(col, row) {
+                    app.current_tab = tab;
+                    app.scroll = 0;
+                }
+            }
+            Event::Mouse(Mouse::ScrollUp) => app.scroll_by(-1),
+            Event::Mouse(Mouse::ScrollDown) => app.scroll_by(1),
+            Event::Tick => {}
         }
         if last_tick.elapsed() >= tick_rate {
+            app.refresh()?;
             last_tick = Instant::now();
         }
     }
 }
 
-fn ui(f: &mut Frame, app: &App) {
+fn ui(f: &mut Frame, app: &mut App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
+            Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Min(0),
             Constraint::Length(3),
@@ -131,46 +222,35 @@ fn ui(f: &mut Frame, app: &App) {
     .block(Block::default().borders(Borders::ALL));
     f.render_widget(header, chunks[0]);
 
-    // Main content
-    let main_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(20), Constraint::Percentage(80)])
-        .split(chunks[1]);
-
-    // Sidebar
-    let items: Vec<ListItem> = app
-        .tabs
-        .iter()
-        .map(|i| {
-            let lines = vec![Line::from(vec![Span::styled(
-                i,
-                Style::default().fg(if app.current_tab == app.tabs.iter().position(|x| x == i).unwrap() {
-                    Color::Yellow
-                } else {
-                    Color::White
-                }),
-            )])];
-            ListItem::new(lines).style(Style::default())
-        })
-        .collect();
-
-    let sidebar = List::new(items)
+    // Tab bar: a full-width single-line bar, since `Tabs` renders
+    // horizontally and a narrow side column would clip titles out of view.
+    let titles: Vec<Line> = app.tabs.iter().map(|t| Line::from(t.as_str())).collect();
+    let tab_bar = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL).title("Menu"))
-        .highlight_style(Style::default().bg(Color::DarkGray));
-    f.render_widget(sidebar, main_chunks[0]);
+        .select(app.current_tab)
+        .divider("│")
+        .style(Style::default().fg(Color::White))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::UNDERLINED | Modifier::BOLD),
+        );
+    f.render_widget(tab_bar, chunks[1]);
+    app.tab_bar_rect = chunks[1];
 
     // Main content area
     let content = match app.current_tab {
-        0 => "Status View\n\n• Working directory clean\n• 3 files staged\n• 2 files modified",
-        1 => "Changes View\n\n• Modified: src/main.rs\n• Staged: README.md\n• Untracked: .gitignore",
-        2 => "History View\n\n• feat: Add new feature\n• fix: Bug fix\n• chore: Update dependencies",
-        3 => "Settings View\n\n• Editor: vim\n• Theme: dark\n• Auto-commit: enabled",
+        0 => status_view(app),
+        1 => changes_view(app),
+        2 => history_view(app),
+        3 => "Settings View\n\n• Editor: vim\n• Theme: dark\n• Auto-commit: enabled".to_string(),
         _ => unreachable!(),
     };
 
     let main_content = Paragraph::new(content)
-        .block(Block::default().borders(Borders::ALL).title(app.tabs[app.current_tab].clone()));
-    f.render_widget(main_content, main_chunks[1]);
+        .block(Block::default().borders(Borders::ALL).title(app.tabs[app.current_tab].clone()))
+        .scroll((app.scroll, 0));
+    f.render_widget(main_content, chunks[2]);
 
     // Footer
     let footer = Paragraph::new(vec![
@@ -180,9 +260,66 @@ fn ui(f: &mut Frame, app: &App) {
                 Style::default().fg(Color::Green),
             ),
             Span::raw(" | "),
-            Span::styled("Press 'q' to quit", Style::default().fg(Color::Gray)),
+            Span::styled(quit_hint(&app.keymap), Style::default().fg(Color::Gray)),
         ]),
     ])
     .block(Block::default().borders(Borders::ALL));
-    f.render_widget(footer, chunks[2]);
+    f.render_widget(footer, chunks[3]);
+}
+
+/// Footer hint for the quit binding, reflecting whatever key `keymap`
+/// actually has bound to [`Action::Quit`] rather than assuming `q`.
+fn quit_hint(keymap: &Keymap) -> String {
+    match keymap.key_for(Action::Quit) {
+        Some(key) => format!("Press {key} to quit"),
+        None => "No quit key bound".to_string(),
+    }
+}
+
+/// Renders the Status tab: a quick summary of staged/modified/untracked counts.
+fn status_view(app: &App) -> String {
+    if app.files.staged.is_empty() && app.files.modified.is_empty() && app.files.untracked.is_empty() {
+        return "Status View\n\n• Working directory clean".to_string();
+    }
+
+    format!(
+        "Status View\n\n• {} files staged\n• {} files modified\n• {} files untracked",
+        app.files.staged.len(),
+        app.files.modified.len(),
+        app.files.untracked.len(),
+    )
+}
+
+/// Renders the Changes tab: the staged/modified/untracked file lists in full.
+fn changes_view(app: &App) -> String {
+    let mut out = String::from("Changes View\n");
+    for path in &app.files.staged {
+        out.push_str(&format!("\n• Staged: {path}"));
+    }
+    for path in &app.files.modified {
+        out.push_str(&format!("\n• Modified: {path}"));
+    }
+    for path in &app.files.untracked {
+        out.push_str(&format!("\n• Untracked: {path}"));
+    }
+    if app.files.staged.is_empty() && app.files.modified.is_empty() && app.files.untracked.is_empty() {
+        out.push_str("\n\n• Working directory clean");
+    }
+    out
+}
+
+/// Renders the History tab: one line per commit, newest first.
+fn history_view(app: &App) -> String {
+    if app.commits.is_empty() {
+        return "History View\n\n• No commits yet".to_string();
+    }
+
+    let mut out = String::from("History View\n");
+    for commit in &app.commits {
+        out.push_str(&format!(
+            "\n• {} {} ({}, {})",
+            commit.hash, commit.summary, commit.author, commit.date
+        ));
+    }
+    out
 }
@@ -0,0 +1,93 @@
+use std::io::{stdout, Stdout};
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::{
+    cursor,
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event as CtEvent, KeyCode, KeyEventKind,
+        MouseButton, MouseEventKind,
+    },
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{backend::CrosstermBackend, Terminal};
+
+use super::{Event, Key, Mouse};
+
+/// Concrete ratatui backend this module hands back from [`init`].
+pub type TerminalBackend = CrosstermBackend<Stdout>;
+
+/// Enables raw mode, switches to the alternate screen, enables mouse
+/// capture, and wraps stdout in a ratatui `Terminal`.
+pub fn init() -> Result<Terminal<TerminalBackend>> {
+    enable_raw_mode()?;
+    let mut out = stdout();
+    execute!(out, EnterAlternateScreen, EnableMouseCapture)?;
+    Ok(Terminal::new(CrosstermBackend::new(out))?)
+}
+
+/// Disables raw mode, leaves the alternate screen and shows the cursor
+/// again. Safe to call more than once.
+pub fn restore() -> Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        cursor::Show
+    )?;
+    Ok(())
+}
+
+/// Polls crossterm for input, normalizing whatever it reports into
+/// [`Event`].
+pub struct Events;
+
+impl Events {
+    pub fn new() -> Events {
+        Events
+    }
+
+    /// Blocks for up to `timeout`, returning `Event::Tick` if nothing
+    /// arrived in time.
+    pub fn next(&self, timeout: Duration) -> Result<Event> {
+        if !event::poll(timeout)? {
+            return Ok(Event::Tick);
+        }
+
+        Ok(match event::read()? {
+            // Crossterm reports both press and release on Windows; a
+            // "release" duplicate would otherwise fire every action twice.
+            CtEvent::Key(key) if key.kind != KeyEventKind::Press => Event::Tick,
+            CtEvent::Key(key) => Event::Key(map_key(key.code)),
+            CtEvent::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => {
+                    Event::Mouse(Mouse::Down(mouse.column, mouse.row))
+                }
+                MouseEventKind::ScrollUp => Event::Mouse(Mouse::ScrollUp),
+                MouseEventKind::ScrollDown => Event::Mouse(Mouse::ScrollDown),
+                _ => Event::Tick,
+            },
+            _ => Event::Tick,
+        })
+    }
+}
+
+fn map_key(code: KeyCode) -> Key {
+    match code {
+        KeyCode::Char(c) => Key::Char(c),
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => Key::BackTab,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        _ => Key::Other,
+    }
+}
@@ -0,0 +1,75 @@
+//! Terminal backend abstraction.
+//!
+//! The crate can be built against either crossterm (default) or termion,
+//! mirroring how `tui-rs` gated its backends behind Cargo features. Exactly
+//! one of the `crossterm`/`termion` features is expected to be enabled;
+//! `run_app` is written once against the small surface exposed here rather
+//! than against either backend crate's types directly.
+
+#[cfg(feature = "crossterm")]
+mod crossterm_backend;
+#[cfg(feature = "termion")]
+mod termion_backend;
+
+#[cfg(feature = "crossterm")]
+pub use crossterm_backend::{init, restore, Events};
+#[cfg(feature = "termion")]
+pub use termion_backend::{init, restore, Events};
+
+/// A single key press, normalized across backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    Char(char),
+    Esc,
+    Enter,
+    Backspace,
+    Left,
+    Right,
+    Up,
+    Down,
+    Tab,
+    BackTab,
+    PageUp,
+    PageDown,
+    Other,
+}
+
+impl std::fmt::Display for Key {
+    /// Renders a key the way it'd be described in help text, e.g. `'q'` or
+    /// `Tab`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Key::Char(c) => write!(f, "'{c}'"),
+            Key::Esc => write!(f, "Esc"),
+            Key::Enter => write!(f, "Enter"),
+            Key::Backspace => write!(f, "Backspace"),
+            Key::Left => write!(f, "Left"),
+            Key::Right => write!(f, "Right"),
+            Key::Up => write!(f, "Up"),
+            Key::Down => write!(f, "Down"),
+            Key::Tab => write!(f, "Tab"),
+            Key::BackTab => write!(f, "Shift+Tab"),
+            Key::PageUp => write!(f, "PageUp"),
+            Key::PageDown => write!(f, "PageDown"),
+            Key::Other => write!(f, "?"),
+        }
+    }
+}
+
+/// A mouse action, normalized across backends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mouse {
+    /// Left button pressed at (column, row).
+    Down(u16, u16),
+    ScrollUp,
+    ScrollDown,
+}
+
+/// One input event from the terminal, or `Tick` when nothing arrived
+/// before the poll timeout elapsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    Key(Key),
+    Mouse(Mouse),
+    Tick,
+}
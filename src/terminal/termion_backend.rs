@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::io::{self, Stdout, Write};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use ratatui::{backend::TermionBackend, Terminal};
+use termion::cursor;
+use termion::event::{Event as TEvent, Key as TKey, MouseButton, MouseEvent as TMouseEvent};
+use termion::input::TermRead;
+use termion::raw::{IntoRawMode, RawTerminal};
+use termion::screen::{ToAlternateScreen, ToMainScreen};
+
+use super::{Event, Key, Mouse};
+
+/// Escape sequences termion's own `MouseTerminal` writes on construction and
+/// drop (see termion's `input.rs`); reproduced here so mouse reporting can
+/// be toggled independent of any wrapper type's lifetime.
+const ENTER_MOUSE_SEQUENCE: &str = "\x1b[?1000h\x1b[?1002h\x1b[?1015h\x1b[?1006h";
+const EXIT_MOUSE_SEQUENCE: &str = "\x1b[?1006l\x1b[?1015l\x1b[?1002l\x1b[?1000l";
+
+thread_local! {
+    /// Holds the raw-mode guard outside of the `Terminal`/backend so
+    /// [`restore`] can drop it on demand instead of waiting on the backend
+    /// itself to be dropped. This is what lets the panic hook installed in
+    /// `main` restore the screen before the default report prints.
+    static RAW_MODE: RefCell<Option<RawTerminal<Stdout>>> = const { RefCell::new(None) };
+}
+
+/// Concrete ratatui backend this module hands back from [`init`].
+pub type TerminalBackend = TermionBackend<Stdout>;
+
+/// Switches stdout into raw mode, enables mouse reporting and the alternate
+/// screen, and wraps it in a ratatui `Terminal`. Unlike wrapping stdout in
+/// termion's `MouseTerminal`/`AlternateScreen` guards, none of this state is
+/// tied to the backend's lifetime, so it can be torn down independent of
+/// `Terminal` via [`restore`].
+pub fn init() -> Result<Terminal<TerminalBackend>> {
+    let raw = io::stdout().into_raw_mode()?;
+    RAW_MODE.with(|cell| *cell.borrow_mut() = Some(raw));
+
+    let mut out = io::stdout();
+    write!(out, "{ToAlternateScreen}{ENTER_MOUSE_SEQUENCE}")?;
+    out.flush()?;
+
+    Ok(Terminal::new(TermionBackend::new(io::stdout()))?)
+}
+
+/// Disables mouse reporting, leaves the alternate screen, shows the cursor
+/// again, and drops raw mode. Safe to call more than once, and callable at
+/// any point rather than only once the backend is dropped — this is what
+/// makes a panic report printed right after visible instead of discarded
+/// when termion's guards unwind.
+pub fn restore() -> Result<()> {
+    RAW_MODE.with(|cell| {
+        cell.borrow_mut().take();
+    });
+
+    let mut out = io::stdout();
+    write!(out, "{EXIT_MOUSE_SEQUENCE}{ToMainScreen}{}", cursor::Show)?;
+    out.flush()?;
+    Ok(())
+}
+
+/// Reads input events from a background thread (termion's `Events`
+/// iterator blocks) and hands them to `next` through a channel, so callers
+/// can poll with the same tick-rate timeout the crossterm backend uses.
+pub struct Events {
+    rx: mpsc::Receiver<Event>,
+}
+
+impl Events {
+    pub fn new() -> Events {
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            let stdin = io::stdin();
+            for event in stdin.events().flatten() {
+                if let Some(event) = map_event(event) {
+                    if tx.send(event).is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+        Events { rx }
+    }
+
+    /// Blocks for up to `timeout`, returning `Event::Tick` if nothing
+    /// arrived in time.
+    pub fn next(&self, timeout: Duration) -> Result<Event> {
+        match self.rx.recv_timeout(timeout) {
+            Ok(event) => Ok(event),
+            Err(mpsc::RecvTimeoutError::Timeout) => Ok(Event::Tick),
+            Err(mpsc::RecvTimeoutError::Disconnected) => Ok(Event::Tick),
+        }
+    }
+}
+
+fn map_event(event: TEvent) -> Option<Event> {
+    match event {
+        TEvent::Key(key) => Some(Event::Key(map_key(key))),
+        TEvent::Mouse(TMouseEvent::Press(MouseButton::Left, x, y)) => {
+            Some(Event::Mouse(Mouse::Down(x.saturating_sub(1), y.saturating_sub(1))))
+        }
+        TEvent::Mouse(TMouseEvent::Press(MouseButton::WheelUp, _, _)) => {
+            Some(Event::Mouse(Mouse::ScrollUp))
+        }
+        TEvent::Mouse(TMouseEvent::Press(MouseButton::WheelDown, _, _)) => {
+            Some(Event::Mouse(Mouse::ScrollDown))
+        }
+        _ => None,
+    }
+}
+
+fn map_key(key: TKey) -> Key {
+    match key {
+        TKey::Char('\n') => Key::Enter,
+        // Termion has no dedicated Tab variant; it reports Tab as the
+        // literal character, same as any other key.
+        TKey::Char('\t') => Key::Tab,
+        TKey::Char(c) => Key::Char(c),
+        TKey::Esc => Key::Esc,
+        TKey::Backspace => Key::Backspace,
+        TKey::Left => Key::Left,
+        TKey::Right => Key::Right,
+        TKey::Up => Key::Up,
+        TKey::Down => Key::Down,
+        TKey::PageUp => Key::PageUp,
+        TKey::PageDown => Key::PageDown,
+        TKey::BackTab => Key::BackTab,
+        _ => Key::Other,
+    }
+}